@@ -0,0 +1,296 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! This tooling subsystem builds on `HashMap` and heap-allocated strings, so like
+//! [`crate::function::disassemble`], it is only available when the `std` feature is enabled.
+#![cfg(feature = "std")]
+
+use crate::{function::{disassemble, Instruction}, Program};
+use snarkvm_circuits::{Literal, Parser};
+
+use core::fmt;
+use std::collections::HashMap;
+
+/// An error encountered while assembling a multi-line Aleo assembly listing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AssembleError {
+    /// An alias was used as an operand but is never defined as a destination anywhere in the listing.
+    UndefinedAlias { alias: String, line: usize, column: usize },
+    /// An alias was defined as a destination more than once.
+    DuplicateAlias { alias: String, line: usize, column: usize },
+    /// An alias was used as an operand before the line on which it is defined.
+    UseBeforeDefinition { alias: String, line: usize, column: usize },
+    /// A line, after alias resolution, could not be parsed into a valid instruction.
+    ParseFailed { line: usize, message: String },
+    /// The assembled instructions could not be serialized to bytecode.
+    SerializationFailed { message: String },
+}
+
+impl fmt::Display for AssembleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::UndefinedAlias { alias, line, column } => {
+                write!(f, "{line}:{column}: register alias '{alias}' is never defined")
+            }
+            Self::DuplicateAlias { alias, line, column } => {
+                write!(f, "{line}:{column}: register alias '{alias}' is defined more than once")
+            }
+            Self::UseBeforeDefinition { alias, line, column } => {
+                write!(f, "{line}:{column}: register alias '{alias}' is used before it is defined")
+            }
+            Self::ParseFailed { line, message } => write!(f, "{line}: {message}"),
+            Self::SerializationFailed { message } => write!(f, "{message}"),
+        }
+    }
+}
+
+impl std::error::Error for AssembleError {}
+
+/// Assembles a multi-line Aleo assembly listing, resolving symbolic register aliases
+/// (e.g. `sum`) to concrete registers (e.g. `r2`) along the way.
+///
+/// Only register aliases are resolved. The instruction set has no branch or label
+/// targets today, so there is nothing for a label pass to resolve; if one is ever
+/// introduced, `resolve_aliases` will need a second pass to go with it.
+pub trait Assemble<P: Program>: Sized {
+    /// Parses `source` into its constituent instructions.
+    fn assemble(source: &str) -> Result<Vec<Self>, AssembleError>;
+
+    /// Parses `source` and serializes the result into the complete bytecode stream.
+    fn assemble_bytes(source: &str) -> Result<Vec<u8>, AssembleError>;
+}
+
+impl<P: Program> Assemble<P> for Instruction<P> {
+    fn assemble(source: &str) -> Result<Vec<Self>, AssembleError> {
+        let resolved = resolve_aliases::<P>(source)?;
+
+        resolved
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(index, line)| {
+                Instruction::<P>::parse(line)
+                    .map(|(_, instruction)| instruction)
+                    .map_err(|error| AssembleError::ParseFailed { line: index + 1, message: error.to_string() })
+            })
+            .collect()
+    }
+
+    fn assemble_bytes(source: &str) -> Result<Vec<u8>, AssembleError> {
+        let instructions = Self::assemble(source)?;
+
+        let mut bytes = Vec::new();
+        disassemble::assemble_bytes(&instructions, &mut bytes)
+            .map_err(|error| AssembleError::SerializationFailed { message: error.to_string() })?;
+
+        Ok(bytes)
+    }
+}
+
+/// Returns the byte offset (0-indexed) at which `token` starts, searching forward from `from`.
+fn find_token_column(line: &str, token: &str, from: usize) -> usize {
+    line[from..].find(token).map(|offset| from + offset).unwrap_or(from)
+}
+
+/// Returns the byte offset (0-indexed) of the standalone `into` keyword token in `line`,
+/// if one appears. Unlike a plain substring search, this does not match `into` occurring
+/// inside a larger token (e.g. an alias or literal that happens to contain it).
+fn find_into_keyword(line: &str) -> Option<usize> {
+    let mut cursor = 0usize;
+    for token in line.split_whitespace() {
+        let start = find_token_column(line, token, cursor);
+        if token == "into" {
+            return Some(start);
+        }
+        cursor = start + token.len();
+    }
+    None
+}
+
+/// Returns `true` if `token` parses as a complete literal, e.g. `1u8` or `true`.
+fn is_literal<P: Program>(token: &str) -> bool {
+    matches!(Literal::<P::Environment>::parse(token), Ok((rest, _)) if rest.is_empty())
+}
+
+/// Returns `true` if `token` is a symbolic register alias candidate, i.e. an identifier
+/// that is neither a concrete register (`r0`, `r1`, ...), the `into` keyword, nor a literal
+/// (e.g. `true`, `false`, `1u8`) that merely happens to be alphabetic.
+fn is_alias_candidate<P: Program>(token: &str) -> bool {
+    let is_concrete_register =
+        token.strip_prefix('r').map(|rest| !rest.is_empty() && rest.chars().all(|c| c.is_ascii_digit())).unwrap_or(false);
+
+    token != "into"
+        && !is_concrete_register
+        && !is_literal::<P>(token)
+        && token.starts_with(|c: char| c.is_ascii_alphabetic())
+        && token.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Performs a two-pass resolution of every symbolic register alias in `source`, and returns
+/// the listing rewritten with concrete `rN` registers in place of aliases.
+///
+/// Pass 1 walks every line to locate destination aliases (the token following `into`),
+/// assigning each a concrete register index in the order it is first defined, and flags
+/// duplicate definitions. Pass 2 walks every line again, replacing operand aliases with
+/// their assigned register, and flags aliases that are undefined or used on an earlier
+/// line than their definition.
+///
+/// This resolves register aliases only; there is no label or branch-target resolution,
+/// since the instruction set has no branch instructions for a label to target.
+fn resolve_aliases<P: Program>(source: &str) -> Result<String, AssembleError> {
+    let lines: Vec<&str> = source.lines().collect();
+
+    // Maps an alias to (concrete register index, line number on which it is defined).
+    let mut definitions: HashMap<String, (u64, usize)> = HashMap::new();
+
+    // Seed the allocator above every concrete `rN` register already written in the listing,
+    // so a listing that mixes `into r0` with alias destinations can never have an alias
+    // collide with a register the author wrote out explicitly.
+    let mut next_register = lines
+        .iter()
+        .flat_map(|line| line.split_whitespace())
+        .filter_map(|token| token.strip_prefix('r'))
+        .filter_map(|index| index.parse::<u64>().ok())
+        .max()
+        .map_or(0, |max_index| max_index + 1);
+
+    // Pass 1: locate destination aliases and assign concrete registers.
+    for (line_index, line) in lines.iter().enumerate() {
+        let line_number = line_index + 1;
+        if let Some(into_offset) = find_into_keyword(line) {
+            let rest = &line[into_offset + "into".len()..];
+            if let Some(alias) = rest.split_whitespace().next() {
+                if is_alias_candidate::<P>(alias) {
+                    let column = find_token_column(line, alias, into_offset) + 1;
+                    if definitions.contains_key(alias) {
+                        return Err(AssembleError::DuplicateAlias { alias: alias.to_string(), line: line_number, column });
+                    }
+                    definitions.insert(alias.to_string(), (next_register, line_number));
+                    next_register += 1;
+                }
+            }
+        }
+    }
+
+    // Pass 2: replace operand aliases, checking they are defined before this line.
+    let mut output = String::with_capacity(source.len());
+    for (line_index, line) in lines.iter().enumerate() {
+        let line_number = line_index + 1;
+        let into_offset = find_into_keyword(line);
+        let operand_region = into_offset.unwrap_or(line.len());
+
+        let mut rewritten = String::with_capacity(line.len());
+        let mut cursor = 0usize;
+        for token in line.split_whitespace() {
+            let start = find_token_column(line, token, cursor);
+            let is_operand = start < operand_region;
+
+            if !rewritten.is_empty() {
+                rewritten.push(' ');
+            }
+
+            if is_operand && is_alias_candidate::<P>(token) {
+                match definitions.get(token) {
+                    None => {
+                        return Err(AssembleError::UndefinedAlias {
+                            alias: token.to_string(),
+                            line: line_number,
+                            column: start + 1,
+                        });
+                    }
+                    Some((_, defined_on)) if *defined_on > line_number => {
+                        return Err(AssembleError::UseBeforeDefinition {
+                            alias: token.to_string(),
+                            line: line_number,
+                            column: start + 1,
+                        });
+                    }
+                    Some((register, _)) => rewritten.push_str(&format!("r{register}")),
+                }
+            } else if is_alias_candidate::<P>(token) {
+                // The destination token; substitute its assigned register.
+                let (register, _) = definitions[token];
+                rewritten.push_str(&format!("r{register}"));
+            } else {
+                rewritten.push_str(token);
+            }
+
+            cursor = start + token.len();
+        }
+
+        output.push_str(&rewritten);
+        output.push('\n');
+    }
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Process;
+
+    type P = Process;
+
+    #[test]
+    fn test_assemble_resolves_aliases() -> anyhow::Result<()> {
+        let source = "sub.w 1u8 2u8 into first\nsub.w 3u8 4u8 into second\nsub.w first second into sum\n";
+        let resolved = resolve_aliases::<P>(source)?;
+        assert_eq!(resolved, "sub.w 1u8 2u8 into r0\nsub.w 3u8 4u8 into r1\nsub.w r0 r1 into r2\n");
+        Ok(())
+    }
+
+    #[test]
+    fn test_assemble_alias_does_not_collide_with_existing_concrete_register() -> anyhow::Result<()> {
+        // `r0` is written out explicitly, so the `sum` alias must not also be assigned `r0`.
+        let source = "sub.w 1u8 2u8 into r0\nsub.w r0 3u8 into sum\n";
+        let resolved = resolve_aliases::<P>(source)?;
+        assert_eq!(resolved, "sub.w 1u8 2u8 into r0\nsub.w r0 3u8 into r1\n");
+        Ok(())
+    }
+
+    #[test]
+    fn test_assemble_undefined_alias() {
+        let source = "sub.w first second into sum\n";
+        let error = Instruction::<P>::assemble(source).unwrap_err();
+        // `first` and `second` are never defined as destinations anywhere in the listing.
+        assert!(matches!(error, AssembleError::UndefinedAlias { .. }));
+    }
+
+    #[test]
+    fn test_assemble_duplicate_alias() {
+        let source = "sub.w a b into sum\nsub.w a b into sum\n";
+        let error = resolve_aliases::<P>(source).unwrap_err();
+        assert!(matches!(error, AssembleError::DuplicateAlias { alias, .. } if alias == "sum"));
+    }
+
+    #[test]
+    fn test_assemble_does_not_mistake_boolean_literal_for_alias() -> anyhow::Result<()> {
+        // `true`/`false` are alphabetic, like an alias, but must be recognized as literals
+        // and left untouched rather than treated as undefined register aliases.
+        let source = "sub.w true false into r0\n";
+        let resolved = resolve_aliases::<P>(source)?;
+        assert_eq!(resolved, source);
+        Ok(())
+    }
+
+    #[test]
+    fn test_assemble_use_before_definition() {
+        let source = "sub.w sum extra into other\nsub.w a b into sum\n";
+        let error = resolve_aliases::<P>(source).unwrap_err();
+        assert!(matches!(error, AssembleError::UseBeforeDefinition { alias, .. } if alias == "sum"));
+    }
+}
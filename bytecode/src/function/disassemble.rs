@@ -0,0 +1,129 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! This tooling subsystem reads and writes bytecode through `std::io`, so it is only
+//! available when the `std` feature is enabled.
+#![cfg(feature = "std")]
+
+use crate::{function::Instruction, Program};
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use std::io::{Read, Result as IoResult, Write};
+
+/// A reader for a single opcode discriminant, dispatched to the matching instruction's `read_le`.
+type OpcodeReader<P> = fn(&mut dyn Read) -> IoResult<Instruction<P>>;
+
+/// Returns the static opcode-to-reader dispatch table, indexed by the single-byte opcode
+/// discriminant that precedes each instruction in the bytecode stream.
+///
+/// Every entry corresponds to exactly one `Instruction` variant, so disassembling an
+/// instruction costs a single array lookup followed by one indirect call, rather than
+/// a linear chain of comparisons against every known opcode.
+fn opcode_table<P: Program>() -> Vec<OpcodeReader<P>> {
+    vec![
+        // 0x00: `sub.w`
+        (|reader| Ok(Instruction::SubWrapped(crate::function::instructions::SubWrapped::read_le(reader)?)))
+            as OpcodeReader<P>,
+    ]
+}
+
+/// Disassembles a length-prefixed stream of bytecode into its constituent instructions.
+pub trait Disassemble<P: Program>: Sized {
+    /// Reads a 4-byte (little-endian) instruction count, followed by that many
+    /// opcode-prefixed instructions, and returns the decoded instructions.
+    fn disassemble<R: Read>(reader: R) -> IoResult<Vec<Self>>;
+
+    /// Reconstructs the Aleo assembly text for a sequence of instructions, one per line.
+    fn to_assembly(instructions: &[Self]) -> String;
+}
+
+impl<P: Program> Disassemble<P> for Instruction<P> {
+    fn disassemble<R: Read>(mut reader: R) -> IoResult<Vec<Self>> {
+        let table = opcode_table::<P>();
+
+        let num_instructions = u32::read_le(&mut reader)?;
+        let mut instructions = Vec::with_capacity(num_instructions as usize);
+
+        for _ in 0..num_instructions {
+            let opcode = u8::read_le(&mut reader)?;
+            let read = table.get(opcode as usize).ok_or_else(|| {
+                std::io::Error::new(std::io::ErrorKind::InvalidData, format!("Invalid opcode '{opcode}'"))
+            })?;
+            instructions.push(read(&mut reader)?);
+        }
+
+        Ok(instructions)
+    }
+
+    fn to_assembly(instructions: &[Self]) -> String {
+        instructions.iter().map(|instruction| instruction.to_string()).collect::<Vec<_>>().join("\n")
+    }
+}
+
+/// Writes the opcode-prefixed bytecode stream for a sequence of instructions.
+pub fn assemble_bytes<P: Program, W: Write>(instructions: &[Instruction<P>], mut writer: W) -> IoResult<()> {
+    (instructions.len() as u32).write_le(&mut writer)?;
+    for instruction in instructions {
+        match instruction {
+            Instruction::SubWrapped(operation) => {
+                0u8.write_le(&mut writer)?;
+                operation.write_le(&mut writer)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Process;
+    use snarkvm_circuits::Parser;
+
+    type P = Process;
+
+    /// Asserts that `parse -> to_bytes -> disassemble -> Display` round-trips to the original text.
+    macro_rules! test_disassemble {
+        ($name:ident, $first:expr, $second:expr) => {
+            #[test]
+            fn $name() -> anyhow::Result<()> {
+                let text = format!("sub.w {} {} into r2", $first, $second);
+                // `Instruction::parse` dispatches on the opcode, unlike `BinaryOperation::parse`,
+                // so it consumes the `sub.w` prefix before delegating to `SubWrapped::parse`.
+                let (_, instruction) = Instruction::<P>::parse(&text).map_err(|e| anyhow::anyhow!("{e}"))?;
+
+                let mut bytes = Vec::new();
+                assemble_bytes(&[instruction], &mut bytes)?;
+
+                let disassembled = Instruction::<P>::disassemble(&bytes[..])?;
+                assert_eq!(Instruction::<P>::to_assembly(&disassembled), text);
+
+                Ok(())
+            }
+        };
+    }
+
+    test_disassemble!(test_disassemble_i8, "-128i8", "1i8");
+    test_disassemble!(test_disassemble_i16, "-32768i16", "1i16");
+    test_disassemble!(test_disassemble_i32, "-2147483648i32", "1i32");
+    test_disassemble!(test_disassemble_i64, "-9223372036854775808i64", "1i64");
+    test_disassemble!(test_disassemble_i128, "-170141183460469231731687303715884105728i128", "1i128");
+    test_disassemble!(test_disassemble_u8, "0u8", "1u8");
+    test_disassemble!(test_disassemble_u16, "0u16", "1u16");
+    test_disassemble!(test_disassemble_u32, "0u32", "1u32");
+    test_disassemble!(test_disassemble_u64, "0u64", "1u64");
+    test_disassemble!(test_disassemble_u128, "0u128", "1u128");
+}
@@ -17,6 +17,7 @@
 use crate::{
     function::{parsers::*, Instruction, Opcode, Operation, Registers},
     helpers::Register,
+    io::{Read, Result as IoResult, Write},
     Program,
     Value,
 };
@@ -25,7 +26,9 @@ use snarkvm_utilities::{FromBytes, ToBytes};
 
 use core::fmt;
 use nom::combinator::map;
-use std::io::{Read, Result as IoResult, Write};
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, vec::Vec};
 
 /// Subtracts `second` from `first`, wrapping around on underflow, and storing the outcome in `destination`.
 pub struct SubWrapped<P: Program> {
@@ -0,0 +1,146 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    function::parsers::Operand,
+    helpers::Register,
+    io::{Read, Result as IoResult, Write},
+    Program,
+};
+use snarkvm_circuits::{Parser, ParserResult};
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use core::fmt;
+use nom::{
+    bytes::complete::tag,
+    character::complete::multispace1,
+    combinator::map,
+    sequence::{pair, preceded},
+};
+
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+
+/// The shared layout for instructions of the form `op first second into destination`.
+pub struct BinaryOperation<P: Program> {
+    first: Operand<P>,
+    second: Operand<P>,
+    destination: Register<P>,
+}
+
+impl<P: Program> BinaryOperation<P> {
+    /// Returns the operands of the operation.
+    pub fn operands(&self) -> Vec<Operand<P>> {
+        vec![self.first.clone(), self.second.clone()]
+    }
+
+    /// Returns the first operand.
+    pub fn first(&self) -> &Operand<P> {
+        &self.first
+    }
+
+    /// Returns the second operand.
+    pub fn second(&self) -> &Operand<P> {
+        &self.second
+    }
+
+    /// Returns the destination register.
+    pub fn destination(&self) -> &Register<P> {
+        &self.destination
+    }
+}
+
+impl<P: Program> Parser for BinaryOperation<P> {
+    type Environment = P::Environment;
+
+    /// Parses a string of the form `first second into destination`.
+    fn parse(string: &str) -> ParserResult<Self> {
+        map(
+            pair(
+                pair(Operand::<P>::parse, preceded(multispace1, Operand::<P>::parse)),
+                preceded(pair(multispace1, pair(tag("into"), multispace1)), Register::<P>::parse),
+            ),
+            |((first, second), destination)| Self { first, second, destination },
+        )(string)
+    }
+}
+
+impl<P: Program> fmt::Display for BinaryOperation<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {} into {}", self.first, self.second, self.destination)
+    }
+}
+
+impl<P: Program> FromBytes for BinaryOperation<P> {
+    /// Reads the two operands followed by the destination register, each of which encodes
+    /// its own register indices using the shared LEB128 varint format.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let first = Operand::read_le(&mut reader)?;
+        let second = Operand::read_le(&mut reader)?;
+        let destination = Register::read_le(&mut reader)?;
+        Ok(Self { first, second, destination })
+    }
+}
+
+impl<P: Program> ToBytes for BinaryOperation<P> {
+    /// Writes the two operands followed by the destination register, each of which encodes
+    /// its own register indices using the shared LEB128 varint format.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.first.write_le(&mut writer)?;
+        self.second.write_le(&mut writer)?;
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{function::parsers::Operand, Process};
+
+    type P = Process;
+
+    /// Returns the register index wrapped by a register operand.
+    fn operand_register_index(operand: &Operand<P>) -> u64 {
+        match operand {
+            Operand::Register(register) => register.index(),
+            Operand::Literal(_) => panic!("expected a register operand"),
+        }
+    }
+
+    fn round_trip(first_index: u64, second_index: u64, destination_index: u64) {
+        let operation = BinaryOperation::<P> {
+            first: Operand::Register(Register::new(first_index)),
+            second: Operand::Register(Register::new(second_index)),
+            destination: Register::new(destination_index),
+        };
+
+        let mut bytes = Vec::new();
+        operation.write_le(&mut bytes).unwrap();
+
+        let recovered = BinaryOperation::<P>::read_le(&bytes[..]).unwrap();
+        assert_eq!(operand_register_index(recovered.first()), first_index);
+        assert_eq!(operand_register_index(recovered.second()), second_index);
+        assert_eq!(recovered.destination().index(), destination_index);
+    }
+
+    // Registers near the LEB128 byte-width boundaries should still round-trip correctly.
+    #[test]
+    fn test_round_trip_boundary_register_indices() {
+        round_trip(0, 1, 2);
+        round_trip(127, 128, 129);
+        round_trip(16383, 16384, 16385);
+    }
+}
@@ -0,0 +1,111 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    helpers::Register,
+    io::{Error, ErrorKind, Read, Result as IoResult, Write},
+    Program,
+};
+use snarkvm_circuits::Literal;
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use core::fmt;
+use nom::{branch::alt, combinator::map};
+
+#[cfg(not(feature = "std"))]
+use alloc::format;
+
+/// An operand to an instruction: either a literal value, or a register holding one.
+pub enum Operand<P: Program> {
+    Literal(Literal<P::Environment>),
+    Register(Register<P>),
+}
+
+impl<P: Program> Clone for Operand<P> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Literal(literal) => Self::Literal(literal.clone()),
+            Self::Register(register) => Self::Register(register.clone()),
+        }
+    }
+}
+
+impl<P: Program> fmt::Display for Operand<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Literal(literal) => write!(f, "{literal}"),
+            Self::Register(register) => write!(f, "{register}"),
+        }
+    }
+}
+
+impl<P: Program> snarkvm_circuits::Parser for Operand<P> {
+    type Environment = P::Environment;
+
+    fn parse(string: &str) -> snarkvm_circuits::ParserResult<Self> {
+        alt((
+            map(Register::<P>::parse, Self::Register),
+            map(Literal::<P::Environment>::parse, Self::Literal),
+        ))(string)
+    }
+}
+
+/// The single-byte discriminant written ahead of an operand to distinguish a literal from a register.
+const LITERAL_TAG: u8 = 0;
+const REGISTER_TAG: u8 = 1;
+
+impl<P: Program> FromBytes for Operand<P> {
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let mut tag = [0u8; 1];
+        reader.read_exact(&mut tag)?;
+        match tag[0] {
+            LITERAL_TAG => Ok(Self::Literal(Literal::read_le(&mut reader)?)),
+            REGISTER_TAG => Ok(Self::Register(Register::read_le(&mut reader)?)),
+            tag => Err(Error::new(ErrorKind::InvalidData, format!("Invalid operand tag '{tag}'"))),
+        }
+    }
+}
+
+impl<P: Program> ToBytes for Operand<P> {
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        match self {
+            Self::Literal(literal) => {
+                LITERAL_TAG.write_le(&mut writer)?;
+                literal.write_le(&mut writer)
+            }
+            Self::Register(register) => {
+                REGISTER_TAG.write_le(&mut writer)?;
+                register.write_le(&mut writer)
+            }
+        }
+    }
+}
+
+// Compiled only under `no_std` builds. `cargo test` always links `std`, so a no_std
+// configuration can only be proven by compiling code under it, not by running a `#[test]`.
+// `Operand::read_le`/`write_le` pass their reader/writer straight through to
+// `Literal::read_le`/`write_le` (i.e. `snarkvm_utilities::{FromBytes, ToBytes}`), which are
+// generic over `snarkvm_utilities::io::{Read, Write}` — the very traits `crate::io` re-exports
+// (see `crate::io`) — so this type-checks with no bridging layer between the two.
+#[cfg(not(feature = "std"))]
+#[allow(dead_code)]
+fn no_std_round_trip_compiles<P: Program>(operand: &Operand<P>) -> IoResult<Operand<P>> {
+    use alloc::vec::Vec;
+
+    let mut bytes = Vec::new();
+    operand.write_le(&mut bytes)?;
+    Operand::<P>::read_le(&bytes[..])
+}
@@ -0,0 +1,134 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unsigned LEB128 encoding, used in the bytecode format for register indices, since almost
+//! all such values are small and a fixed-width encoding would waste bytes.
+
+use crate::io::{Error, ErrorKind, Read, Result as IoResult, Write};
+
+/// Writes `value` to `writer` as an unsigned LEB128 varint: the low 7 bits of `value` are
+/// emitted per byte, with the high (continuation) bit set on every byte except the last.
+pub fn write_leb128<W: Write>(mut value: u64, mut writer: W) -> IoResult<()> {
+    loop {
+        let mut byte = (value & 0x7F) as u8;
+        value >>= 7;
+        if value != 0 {
+            byte |= 0x80;
+        }
+        writer.write_all(&[byte])?;
+        if value == 0 {
+            return Ok(());
+        }
+    }
+}
+
+/// Reads an unsigned LEB128 varint from `reader`, accumulating 7 bits per byte until a byte
+/// without its continuation bit set is encountered.
+///
+/// Errors if the value overflows a `u64`, or if the encoding is non-canonical (i.e. it ends
+/// in a trailing all-zero byte that contributes no bits beyond what a shorter encoding would).
+pub fn read_leb128<R: Read>(mut reader: R) -> IoResult<u64> {
+    let mut value = 0u64;
+    let mut shift = 0u32;
+    let mut previous_byte = None;
+
+    loop {
+        let mut buffer = [0u8; 1];
+        reader.read_exact(&mut buffer)?;
+        let byte = buffer[0];
+
+        if shift >= 64 || (shift == 63 && (byte & 0x7E) != 0) {
+            return Err(Error::new(ErrorKind::InvalidData, "LEB128 value overflows a u64"));
+        }
+
+        value |= u64::from(byte & 0x7F) << shift;
+        shift += 7;
+        previous_byte = Some(byte);
+
+        if byte & 0x80 == 0 {
+            break;
+        }
+    }
+
+    // A continuation byte that encodes zero contributes no information, so the canonical
+    // encoding never ends with one: it would be shorter without it.
+    if shift > 7 && previous_byte == Some(0x00) {
+        return Err(Error::new(ErrorKind::InvalidData, "Non-canonical LEB128 encoding"));
+    }
+
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(value: u64) {
+        let mut bytes = Vec::new();
+        write_leb128(value, &mut bytes).unwrap();
+        assert_eq!(read_leb128(&bytes[..]).unwrap(), value);
+    }
+
+    #[test]
+    fn test_round_trip_small_values() {
+        for value in 0..300u64 {
+            round_trip(value);
+        }
+    }
+
+    #[test]
+    fn test_round_trip_boundary_values() {
+        // The boundary between a 1-byte and 2-byte encoding.
+        round_trip(127);
+        round_trip(128);
+        // The boundary between a 2-byte and 3-byte encoding.
+        round_trip(16383);
+        round_trip(16384);
+    }
+
+    #[test]
+    fn test_one_byte_encoding_length() {
+        let mut bytes = Vec::new();
+        write_leb128(127, &mut bytes).unwrap();
+        assert_eq!(bytes, vec![0x7F]);
+    }
+
+    #[test]
+    fn test_two_byte_encoding_length() {
+        let mut bytes = Vec::new();
+        write_leb128(128, &mut bytes).unwrap();
+        assert_eq!(bytes, vec![0x80, 0x01]);
+    }
+
+    #[test]
+    fn test_three_byte_boundary_encoding_length() {
+        let mut bytes = Vec::new();
+        write_leb128(16384, &mut bytes).unwrap();
+        assert_eq!(bytes, vec![0x80, 0x80, 0x01]);
+    }
+
+    #[test]
+    fn test_round_trip_u64_max() {
+        round_trip(u64::MAX);
+    }
+
+    #[test]
+    fn test_overflow_errors() {
+        // 10 bytes, each with the continuation bit set, overflows a u64.
+        let bytes = vec![0xFF; 10];
+        assert!(read_leb128(&bytes[..]).is_err());
+    }
+}
@@ -0,0 +1,112 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{helpers::leb128::{read_leb128, write_leb128}, io::{Read, Result as IoResult, Write}, Program};
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use core::{fmt, marker::PhantomData};
+use nom::{
+    character::complete::{char, digit1},
+    combinator::map_res,
+    sequence::preceded,
+};
+
+/// A register in a function, e.g. `r0`, referenced by its index.
+pub struct Register<P> {
+    index: u64,
+    _phantom: PhantomData<P>,
+}
+
+impl<P> Register<P> {
+    /// Returns a new register with the given index.
+    pub fn new(index: u64) -> Self {
+        Self { index, _phantom: PhantomData }
+    }
+
+    /// Returns the index of the register.
+    pub fn index(&self) -> u64 {
+        self.index
+    }
+}
+
+impl<P> Clone for Register<P> {
+    fn clone(&self) -> Self {
+        Self::new(self.index)
+    }
+}
+
+impl<P> PartialEq for Register<P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index
+    }
+}
+
+impl<P> Eq for Register<P> {}
+
+impl<P> fmt::Display for Register<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "r{}", self.index)
+    }
+}
+
+impl<P: Program> snarkvm_circuits::Parser for Register<P> {
+    type Environment = P::Environment;
+
+    /// Parses a string of the form `r{index}` into a register.
+    fn parse(string: &str) -> snarkvm_circuits::ParserResult<Self> {
+        map_res(preceded(char('r'), digit1), |index: &str| index.parse::<u64>().map(Self::new))(string)
+    }
+}
+
+impl<P> FromBytes for Register<P> {
+    /// Reads the register index as an unsigned LEB128 varint, since register indices are
+    /// almost always small and a fixed-width encoding would waste bytes.
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        Ok(Self::new(read_leb128(reader)?))
+    }
+}
+
+impl<P> ToBytes for Register<P> {
+    /// Writes the register index as an unsigned LEB128 varint.
+    fn write_le<W: Write>(&self, writer: W) -> IoResult<()> {
+        write_leb128(self.index, writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Process;
+
+    type P = Process;
+
+    fn round_trip(index: u64) {
+        let register = Register::<P>::new(index);
+        let mut bytes = Vec::new();
+        register.write_le(&mut bytes).unwrap();
+        assert_eq!(Register::<P>::read_le(&bytes[..]).unwrap().index(), register.index());
+    }
+
+    #[test]
+    fn test_register_round_trip() {
+        round_trip(0);
+        round_trip(1);
+        round_trip(127);
+        round_trip(128);
+        round_trip(16383);
+        round_trip(16384);
+    }
+}
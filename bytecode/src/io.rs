@@ -0,0 +1,26 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A `Read`/`Write` abstraction for the bytecode format.
+//!
+//! `snarkvm_utilities::{FromBytes, ToBytes}` — which every literal, register, and operand
+//! already serializes through — are themselves generic over `snarkvm_utilities::io::{Read,
+//! Write}`, not `std::io`, precisely so they work under `no_std`. We re-export that same pair
+//! of traits here rather than inventing a second one, so that e.g. `Operand::read_le`, which
+//! must pass its reader straight through to `Literal::read_le`, type-checks under both `std`
+//! and `no_std` without a bridging layer: there is only ever one `Read`/`Write` bound in play.
+
+pub use snarkvm_utilities::io::{Error, ErrorKind, Read, Result, Write};